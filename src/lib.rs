@@ -15,6 +15,8 @@
 //! **Very important**: Abomonation reproduces the memory as laid out by the serializer, which can
 //! reveal architectural variations. Data encoded on a 32bit big-endian machine will not decode
 //! properly on a 64bit little-endian machine. Ideally it won't eat your laundry, but rather panic.
+//! Use `encode_canonical`/`decode_canonical` instead of `encode`/`decode` if the data needs to
+//! survive a trip across machines of differing endianness.
 //!
 //!
 //! #Examples
@@ -27,7 +29,7 @@
 //!
 //! // encode vector into a Vec<u8>
 //! let mut bytes = Vec::new();
-//! encode(&vector[..], &mut bytes);
+//! encode(&vector[..], &mut bytes).unwrap();
 //!
 //! // decode a &Vec<(u64, String)> from binary data
 //! if let Ok(result) = decode::<(u64, String)>(&mut bytes) {
@@ -38,16 +40,21 @@
 // extern crate num;
 
 use std::mem;       // yup, used pretty much everywhere.
-use std::io::Write; // for bytes.write_all; push_all is unstable and extend is slow.
+use std::io::{self, Write}; // for bytes.write_all; push_all is unstable and extend is slow.
 // use std::traits::PrimInt;
 
-/// Encodes a vector of typed data into a binary buffer.
+/// Encodes a vector of typed data into a binary writer.
 ///
-/// `encode` will transmute `typed` to binary and write its contents to `bytes`. After doing this,
+/// `encode` will transmute `typed` to binary and write its contents to `write`. After doing this,
 /// it will offer each element of typed the opportunity to serialize more data. Having done that,
 /// it offers each element the opportunity to "tidy up", in which the elements can erasing things
 /// like local memory addresses that it would be impolite to share.
 ///
+/// Because `write` need only implement `Write`, `encode` can stream directly into a file, a
+/// socket, or a compressing wrapper rather than requiring the caller to first buffer everything
+/// into a `Vec<u8>`. Callers who do write into a `Vec<u8>` and want to avoid repeated reallocation
+/// should use `encode_reserved` instead, which reserves the exact capacity up front.
+///
 /// #Examples
 /// ```
 /// use abomonation::{encode, decode};
@@ -58,7 +65,7 @@ use std::io::Write; // for bytes.write_all; push_all is unstable and extend is s
 ///
 /// // encode vector into a Vec<u8>
 /// let mut bytes = Vec::new();
-/// encode(&vector[..], &mut bytes);
+/// encode(&vector[..], &mut bytes).unwrap();
 ///
 /// // decode a &Vec<(u64, String)> from binary data
 /// if let Ok(result) = decode::<(u64, String)>(&mut bytes) {
@@ -66,14 +73,42 @@ use std::io::Write; // for bytes.write_all; push_all is unstable and extend is s
 /// }
 /// ```
 ///
-pub fn encode<T: Abomonation>(typed: &[T], bytes: &mut Vec<u8>) {
+pub fn encode<T: Abomonation, W: Write>(typed: &[T], write: &mut W) -> io::Result<()> {
     unsafe {
+        let mut bytes: Vec<u8> = Vec::with_capacity(mem::size_of::<&[T]>());
         let slice = std::slice::from_raw_parts(mem::transmute(&typed), mem::size_of::<&[T]>());
         bytes.write_all(slice).unwrap();    // a write to a Vec<u8> is claimed to never fail.
         let result: &mut Vec<T> = mem::transmute(bytes.get_unchecked_mut(0));
         result.embalm();
-        typed.entomb(bytes);
+        try!(write.write_all(&bytes[..]));
+        try!(typed.entomb(write));
     }
+    Ok(())
+}
+
+/// Encodes `typed` into `bytes`, reserving its exact encoded size up front.
+///
+/// `encode_reserved` behaves exactly as `encode`, except that it first reserves
+/// `typed.extent()` bytes (plus the small fixed-size header) on `bytes`, so the writes that
+/// follow never trigger a reallocation of `bytes` itself.
+///
+/// #Examples
+/// ```
+/// use abomonation::{encode_reserved, decode};
+///
+/// let vector = (0..256u64).map(|i| (i, format!("{}", i)))
+///                         .collect::<Vec<_>>();
+///
+/// let mut bytes = Vec::new();
+/// encode_reserved(&vector[..], &mut bytes).unwrap();
+///
+/// if let Ok(result) = decode::<(u64, String)>(&mut bytes) {
+///     assert!(result == &vector[..]);
+/// }
+/// ```
+pub fn encode_reserved<T: Abomonation>(typed: &[T], bytes: &mut Vec<u8>) -> io::Result<()> {
+    bytes.reserve(mem::size_of::<&[T]>() + typed.extent());
+    encode(typed, bytes)
 }
 
 /// Decodes a binary buffer into a reference to a typed vector.
@@ -92,20 +127,127 @@ pub fn encode<T: Abomonation>(typed: &[T], bytes: &mut Vec<u8>) {
 ///
 /// // encode vector into a Vec<u8>
 /// let mut bytes = Vec::new();
-/// encode(&vector[..], &mut bytes);
+/// encode(&vector[..], &mut bytes).unwrap();
 ///
 /// // decode a &Vec<(u64, String)> from binary data
 /// if let Ok(result) = decode::<(u64, String)>(&mut bytes) {
 ///     assert!(result == &vector[..]);
 /// }
+///
+/// // a buffer that isn't aligned for `T` is rejected rather than risking undefined behavior
+/// use abomonation::AlignedBytes;
+/// let small = vec![1u64, 2, 3];
+/// let mut small_bytes = Vec::new();
+/// encode(&small[..], &mut small_bytes).unwrap();
+/// let mut misaligned = AlignedBytes::new(small_bytes.len() + 1);
+/// misaligned[1..].copy_from_slice(&small_bytes[..]);
+/// assert!(decode::<u64>(&mut misaligned[1..]).is_err());
+///
+/// // a buffer too short to even hold the header is rejected rather than panicking in split_at_mut
+/// assert!(decode::<u64>(&mut [1u8, 2, 3]).is_err());
 /// ```
 pub fn decode<T: Abomonation>(bytes: &mut [u8]) -> Result<&[T], &mut [u8]> {
+    // `split1` is about to be transmuted into a `&mut &[T]`, a fat pointer, so it must be aligned
+    // to a pointer's alignment regardless of `T`'s own (e.g. T = u8 would otherwise pass trivially).
+    // We also need enough bytes for the header itself, or split_at_mut below would panic.
+    if bytes.len() < mem::size_of::<&[T]>() || !is_aligned::<&[T]>(bytes.as_ptr()) { return Err(bytes); }
     let (split1, split2) = bytes.split_at_mut(mem::size_of::<&[T]>());
     let result: &mut &[T] = unsafe { mem::transmute(split1.get_unchecked_mut(0)) };
     unsafe { try!(result.exhume(split2)); }
     Ok(result)
 }
 
+/// Reads encoded bytes out of an `AlignedBytes` buffer, whose start is 8-byte aligned and so
+/// passes the alignment check `decode` makes at the head of the buffer. Use this instead of
+/// `decode` whenever `bytes` did not come from a source known to be sufficiently aligned; a plain
+/// `Vec<u8>` makes no such promise. Owned data further into the buffer is checked just as `decode`
+/// checks it: a `Vec<u64>` whose bytes follow an odd-length `String`, for example, is misaligned
+/// and yields `Err`.
+///
+/// #Examples
+/// ```
+/// use abomonation::{encode, decode_aligned, AlignedBytes};
+///
+/// let vector = vec![1u64, 2, 3, 4, 5];
+///
+/// let mut encoded = Vec::new();
+/// encode(&vector[..], &mut encoded).unwrap();
+///
+/// let mut bytes = AlignedBytes::new(encoded.len());
+/// bytes.copy_from_slice(&encoded[..]);
+///
+/// if let Ok(result) = decode_aligned::<u64>(&mut bytes) {
+///     assert!(result == &vector[..]);
+/// }
+/// ```
+pub fn decode_aligned<T: Abomonation>(bytes: &mut AlignedBytes) -> Result<&[T], &mut [u8]> {
+    decode(&mut bytes[..])
+}
+
+/// Encodes a vector of typed data into a binary writer, using a canonical little-endian layout.
+///
+/// `encode_canonical` behaves exactly as `encode`, except that every scalar field is byte-swapped
+/// to little-endian on the way out, including the element count of `typed` itself. Data written
+/// this way can be `decode_canonical`d on a big-endian host, which plain `encode`/`decode` cannot
+/// promise (see the module docs). On a little-endian host the swaps are no-ops, so there is no
+/// cost where it isn't needed.
+///
+/// #Examples
+/// ```
+/// use abomonation::{encode_canonical, decode_canonical};
+///
+/// let vector = vec![1u64, 2, 3, 4, 5];
+///
+/// let mut bytes = Vec::new();
+/// encode_canonical(&vector[..], &mut bytes).unwrap();
+///
+/// if let Ok(result) = decode_canonical::<u64>(&mut bytes) {
+///     assert!(result == &vector[..]);
+/// }
+/// ```
+pub fn encode_canonical<T: Abomonation, W: Write>(typed: &[T], write: &mut W) -> io::Result<()> {
+    unsafe {
+        let mut bytes: Vec<u8> = Vec::with_capacity(mem::size_of::<&[T]>());
+        let slice = std::slice::from_raw_parts(mem::transmute(&typed), mem::size_of::<&[T]>());
+        bytes.write_all(slice).unwrap();    // a write to a Vec<u8> is claimed to never fail.
+        let result: &mut Vec<T> = mem::transmute(bytes.get_unchecked_mut(0));
+        result.embalm_canonical();          // also swaps the element count to little-endian
+        try!(write.write_all(&bytes[..]));
+        try!(typed.entomb_canonical(write));
+    }
+    Ok(())
+}
+
+/// Decodes a canonical little-endian binary buffer into a reference to a typed vector.
+///
+/// `decode_canonical` is the counterpart to `encode_canonical`: it undoes the little-endian swap
+/// of every scalar field, including the element count of the returned slice, as part of the usual
+/// `exhume`; each element count is swapped back before it is used to split `bytes`.
+///
+/// #Examples
+/// ```
+/// use abomonation::{encode_canonical, decode_canonical};
+///
+/// let vector = (0..256u64).map(|i| (i, format!("{}", i)))
+///                         .collect::<Vec<_>>();
+///
+/// let mut bytes = Vec::new();
+/// encode_canonical(&vector[..], &mut bytes).unwrap();
+///
+/// if let Ok(result) = decode_canonical::<(u64, String)>(&mut bytes) {
+///     assert!(result == &vector[..]);
+/// }
+/// ```
+pub fn decode_canonical<T: Abomonation>(bytes: &mut [u8]) -> Result<&[T], &mut [u8]> {
+    // see the comment in `decode`: this transmutes to a `&mut &[T]` fat pointer, not a `T`, and
+    // needs enough bytes for the header itself, or split_at_mut below would panic.
+    if bytes.len() < mem::size_of::<&[T]>() || !is_aligned::<&[T]>(bytes.as_ptr()) { return Err(bytes); }
+    let (split1, split2) = bytes.split_at_mut(mem::size_of::<&[T]>());
+    let result: &mut &[T] = unsafe { mem::transmute(split1.get_unchecked_mut(0)) };
+    unsafe { try!(result.exhume_canonical(split2)); }
+    Ok(result)
+}
+
 /// Abomonation provides methods to serialize any heap data the implementor owns.
 ///
 /// The default implementations for Abomonation's methods are all empty. Many types have no owned
@@ -120,8 +262,10 @@ pub trait Abomonation {
 
     /// Write any additional information about `&self` beyond its binary representation.
     ///
-    /// Most commonly this is owned data on the other end of pointers in `&self`.
-    unsafe fn entomb(&self, _writer: &mut Vec<u8>) { }
+    /// Most commonly this is owned data on the other end of pointers in `&self`. The writer is
+    /// generic so that the bytes can stream directly into their final destination rather than
+    /// first being accumulated in memory.
+    unsafe fn entomb<W: Write>(&self, _write: &mut W) -> io::Result<()> { Ok(()) }
 
     /// Perform any final edits before committing `&mut self`.
     ///
@@ -132,45 +276,130 @@ pub trait Abomonation {
     ///
     /// Most commonly this populates pointers with valid references into `bytes`.
     unsafe fn exhume<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> { Ok(bytes) }
+
+    /// Reports the number of further bytes required to entomb `self`.
+    ///
+    /// Most commonly this is the size of owned data on the other end of pointers in `&self`, and
+    /// lets a caller who is accumulating encoded bytes into a `Vec<u8>` reserve the exact capacity
+    /// up front rather than growing the buffer through repeated reallocations.
+    fn extent(&self) -> usize { 0 }
+
+    /// As `entomb`, but for use by `encode_canonical`: recurses through `entomb_canonical` so that
+    /// nested scalar fields are byte-swapped to little-endian on the way out.
+    unsafe fn entomb_canonical<W: Write>(&self, write: &mut W) -> io::Result<()> { self.entomb(write) }
+
+    /// As `embalm`, but additionally normalizes scalar fields to little-endian.
+    ///
+    /// Most scalar types have no pointers to scrub and simply byte-swap themselves; composite
+    /// types forward to the `embalm_canonical` of their fields.
+    unsafe fn embalm_canonical(&mut self) { self.embalm() }
+
+    /// As `exhume`, but additionally normalizes scalar fields back from little-endian.
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> { self.exhume(bytes) }
 }
 
 impl Abomonation for u8 { }
-impl Abomonation for u16 { }
-impl Abomonation for u32 { }
+impl Abomonation for u16 {
+    unsafe fn embalm_canonical(&mut self) { *self = self.to_le(); }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+        *self = u16::from_le(*self); Ok(bytes)
+    }
+}
+impl Abomonation for u32 {
+    unsafe fn embalm_canonical(&mut self) { *self = self.to_le(); }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+        *self = u32::from_le(*self); Ok(bytes)
+    }
+}
 impl Abomonation for u64 {
     // TODO : if these were optimized out, hooray! unfortunately, they aren't.
     // unsafe fn embalm(&mut self) { *self = (*self).to_le(); }
     // unsafe fn exhume(&mut self, bytes: &mut &[u8]) -> Result<(), ()> { *self = u64::from_le(*self); Ok(()) }
+    unsafe fn embalm_canonical(&mut self) { *self = self.to_le(); }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+        *self = u64::from_le(*self); Ok(bytes)
+    }
 }
 
 impl Abomonation for i8 { }
-impl Abomonation for i16 { }
-impl Abomonation for i32 { }
-impl Abomonation for i64 { }
+impl Abomonation for i16 {
+    unsafe fn embalm_canonical(&mut self) { *self = self.to_le(); }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+        *self = i16::from_le(*self); Ok(bytes)
+    }
+}
+impl Abomonation for i32 {
+    unsafe fn embalm_canonical(&mut self) { *self = self.to_le(); }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+        *self = i32::from_le(*self); Ok(bytes)
+    }
+}
+impl Abomonation for i64 {
+    unsafe fn embalm_canonical(&mut self) { *self = self.to_le(); }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+        *self = i64::from_le(*self); Ok(bytes)
+    }
+}
 
-impl Abomonation for f32 { }
-impl Abomonation for f64 { }
+impl Abomonation for f32 {
+    unsafe fn embalm_canonical(&mut self) { *self = f32::from_bits(self.to_bits().to_le()); }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+        *self = f32::from_bits(u32::from_le(self.to_bits())); Ok(bytes)
+    }
+}
+impl Abomonation for f64 {
+    unsafe fn embalm_canonical(&mut self) { *self = f64::from_bits(self.to_bits().to_le()); }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+        *self = f64::from_bits(u64::from_le(self.to_bits())); Ok(bytes)
+    }
+}
 
 impl Abomonation for bool { }
 
-impl<T: Abomonation> Abomonation for Option<T> { }
+impl<T: Abomonation> Abomonation for Option<T> {
+    unsafe fn embalm_canonical(&mut self) { if let Some(ref mut inner) = *self { inner.embalm_canonical(); } }
+    unsafe fn entomb_canonical<W: Write>(&self, write: &mut W) -> io::Result<()> {
+        if let Some(ref inner) = *self { try!(inner.entomb_canonical(write)); }
+        Ok(())
+    }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+        if let Some(ref mut inner) = *self { inner.exhume_canonical(bytes) } else { Ok(bytes) }
+    }
+}
 
 impl<T1: Abomonation, T2: Abomonation> Abomonation for (T1, T2) {
     unsafe fn embalm(&mut self) { self.0.embalm(); self.1.embalm(); }
-    unsafe fn entomb(&self, bytes: &mut Vec<u8>) { self.0.entomb(bytes); self.1.entomb(bytes); }
+    unsafe fn entomb<W: Write>(&self, write: &mut W) -> io::Result<()> {
+        try!(self.0.entomb(write));
+        try!(self.1.entomb(write));
+        Ok(())
+    }
     unsafe fn exhume<'a,'b>(&'a mut self, mut bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
         let tmp = bytes; bytes = try!(self.0.exhume(tmp));
         let tmp = bytes; bytes = try!(self.1.exhume(tmp));
         Ok(bytes)
     }
+    fn extent(&self) -> usize { self.0.extent() + self.1.extent() }
+    unsafe fn embalm_canonical(&mut self) { self.0.embalm_canonical(); self.1.embalm_canonical(); }
+    unsafe fn entomb_canonical<W: Write>(&self, write: &mut W) -> io::Result<()> {
+        try!(self.0.entomb_canonical(write));
+        try!(self.1.entomb_canonical(write));
+        Ok(())
+    }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, mut bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+        let tmp = bytes; bytes = try!(self.0.exhume_canonical(tmp));
+        let tmp = bytes; bytes = try!(self.1.exhume_canonical(tmp));
+        Ok(bytes)
+    }
 }
 
 impl Abomonation for String {
     unsafe fn embalm(&mut self) {
         std::ptr::write(self, String::from_raw_parts(0 as *mut u8, self.len(), self.len()));
     }
-    unsafe fn entomb(&self, bytes: &mut Vec<u8>) {
-        bytes.write_all(self.as_bytes()).unwrap();
+    unsafe fn entomb<W: Write>(&self, write: &mut W) -> io::Result<()> {
+        try!(write.write_all(self.as_bytes()));
+        Ok(())
     }
     unsafe fn exhume<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
         if self.len() > bytes.len() { Err(bytes) }
@@ -180,23 +409,39 @@ impl Abomonation for String {
             Ok(rest)
         }
     }
+    fn extent(&self) -> usize { self.len() }
+    unsafe fn embalm_canonical(&mut self) {
+        let len = self.len();
+        std::ptr::write(self, String::from_raw_parts(0 as *mut u8, len.to_le(), len.to_le()));
+    }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+        let len = usize::from_le(self.len());
+        if len > bytes.len() { Err(bytes) }
+        else {
+            let (mine, rest) = bytes.split_at_mut(len);
+            std::ptr::write(self, String::from_raw_parts(mem::transmute(mine.as_ptr()), len, len));
+            Ok(rest)
+        }
+    }
 }
 
 impl<T: Abomonation> Abomonation for Vec<T> {
     unsafe fn embalm(&mut self) {
         std::ptr::write(self, Vec::from_raw_parts(0 as *mut T, self.len(), self.len()));
     }
-    unsafe fn entomb(&self, bytes: &mut Vec<u8>) {
-        let position = bytes.len();
+    unsafe fn entomb<W: Write>(&self, write: &mut W) -> io::Result<()> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(mem::size_of::<T>() * self.len());
         bytes.write_all(typed_to_bytes(&self[..])).unwrap();
-        for element in bytes_to_typed::<T>(&mut bytes[position..], self.len()) { element.embalm(); }
-        for element in self.iter() { element.entomb(bytes); }
+        for element in bytes_to_typed::<T>(&mut bytes[..], self.len()) { element.embalm(); }
+        try!(write.write_all(&bytes[..]));
+        for element in self.iter() { try!(element.entomb(write)); }
+        Ok(())
     }
     unsafe fn exhume<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
 
         // extract memory from bytes to back our vector
         let binary_len = self.len() * mem::size_of::<T>();
-        if binary_len > bytes.len() { Err(bytes) }
+        if binary_len > bytes.len() || !is_aligned::<T>(bytes.as_ptr()) { Err(bytes) }
         else {
             let (mine, mut rest) = bytes.split_at_mut(binary_len);
             let slice = std::slice::from_raw_parts_mut(mine.as_mut_ptr() as *mut T, self.len());
@@ -208,23 +453,57 @@ impl<T: Abomonation> Abomonation for Vec<T> {
             Ok(rest)
         }
     }
+    fn extent(&self) -> usize {
+        mem::size_of::<T>() * self.len() + self.iter().map(|e| e.extent()).sum::<usize>()
+    }
+    unsafe fn entomb_canonical<W: Write>(&self, write: &mut W) -> io::Result<()> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(mem::size_of::<T>() * self.len());
+        bytes.write_all(typed_to_bytes(&self[..])).unwrap();
+        for element in bytes_to_typed::<T>(&mut bytes[..], self.len()) { element.embalm_canonical(); }
+        try!(write.write_all(&bytes[..]));
+        for element in self.iter() { try!(element.entomb_canonical(write)); }
+        Ok(())
+    }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+
+        // our own length/capacity were written little-endian; recover the native value first
+        let len = usize::from_le(self.len());
+        let binary_len = len * mem::size_of::<T>();
+        if binary_len > bytes.len() || !is_aligned::<T>(bytes.as_ptr()) { Err(bytes) }
+        else {
+            let (mine, mut rest) = bytes.split_at_mut(binary_len);
+            let slice = std::slice::from_raw_parts_mut(mine.as_mut_ptr() as *mut T, len);
+            std::ptr::write(self, Vec::from_raw_parts(slice.as_mut_ptr(), len, len));
+            for element in self.iter_mut() {
+                let temp = rest;             // temp variable explains lifetimes (mysterious!)
+                rest = try!(element.exhume_canonical(temp));
+            }
+            Ok(rest)
+        }
+    }
+    unsafe fn embalm_canonical(&mut self) {
+        let len = self.len();
+        std::ptr::write(self, Vec::from_raw_parts(0 as *mut T, len.to_le(), len.to_le()));
+    }
 }
 
 impl<'c, T: Abomonation> Abomonation for &'c [T] {
     unsafe fn embalm(&mut self) {
         std::ptr::write(self, std::slice::from_raw_parts(0 as *mut T, self.len()));
     }
-    unsafe fn entomb(&self, bytes: &mut Vec<u8>) {
-        let position = bytes.len();
+    unsafe fn entomb<W: Write>(&self, write: &mut W) -> io::Result<()> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(mem::size_of::<T>() * self.len());
         bytes.write_all(typed_to_bytes(self)).unwrap();
-        for element in bytes_to_typed::<T>(&mut bytes[position..], self.len()) { element.embalm(); }
-        for element in self.iter() { element.entomb(bytes); }
+        for element in bytes_to_typed::<T>(&mut bytes[..], self.len()) { element.embalm(); }
+        try!(write.write_all(&bytes[..]));
+        for element in self.iter() { try!(element.entomb(write)); }
+        Ok(())
     }
     unsafe fn exhume<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
 
         // extract memory from bytes to back our vector
         let binary_len = self.len() * mem::size_of::<T>();
-        if binary_len > bytes.len() { Err(bytes) }
+        if binary_len > bytes.len() || !is_aligned::<T>(bytes.as_ptr()) { Err(bytes) }
         else {
             let (mine, mut rest) = bytes.split_at_mut(binary_len);
             let slice = std::slice::from_raw_parts_mut(mine.as_mut_ptr() as *mut T, self.len());
@@ -236,6 +515,38 @@ impl<'c, T: Abomonation> Abomonation for &'c [T] {
             Ok(rest)
         }
     }
+    fn extent(&self) -> usize {
+        mem::size_of::<T>() * self.len() + self.iter().map(|e| e.extent()).sum::<usize>()
+    }
+    unsafe fn entomb_canonical<W: Write>(&self, write: &mut W) -> io::Result<()> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(mem::size_of::<T>() * self.len());
+        bytes.write_all(typed_to_bytes(self)).unwrap();
+        for element in bytes_to_typed::<T>(&mut bytes[..], self.len()) { element.embalm_canonical(); }
+        try!(write.write_all(&bytes[..]));
+        for element in self.iter() { try!(element.entomb_canonical(write)); }
+        Ok(())
+    }
+    unsafe fn exhume_canonical<'a,'b>(&'a mut self, bytes: &'b mut [u8]) -> Result<&'b mut [u8], &'b mut [u8]> {
+
+        // our own length was written little-endian; recover the native value first
+        let len = usize::from_le(self.len());
+        let binary_len = len * mem::size_of::<T>();
+        if binary_len > bytes.len() || !is_aligned::<T>(bytes.as_ptr()) { Err(bytes) }
+        else {
+            let (mine, mut rest) = bytes.split_at_mut(binary_len);
+            let slice = std::slice::from_raw_parts_mut(mine.as_mut_ptr() as *mut T, len);
+            for element in slice.iter_mut() {
+                let temp = rest;
+                rest = try!(element.exhume_canonical(temp));
+            }
+            std::ptr::write(self, slice);   // <-- avoids dropping any referents (invalid anyhow)
+            Ok(rest)
+        }
+    }
+    unsafe fn embalm_canonical(&mut self) {
+        let len = self.len();
+        std::ptr::write(self, std::slice::from_raw_parts(0 as *mut T, len.to_le()));
+    }
 }
 
 
@@ -246,3 +557,40 @@ unsafe fn typed_to_bytes<T>(slice: &[T]) -> &[u8] {
 unsafe fn bytes_to_typed<T>(slice: &mut [u8], len: usize) -> &mut [T] {
     std::slice::from_raw_parts_mut(slice.as_mut_ptr() as *mut T, len)
 }
+
+fn is_aligned<T>(ptr: *const u8) -> bool {
+    (ptr as usize) % mem::align_of::<T>() == 0
+}
+
+/// A byte buffer guaranteed to be aligned to 8 bytes.
+///
+/// `decode` transmutes its input into `&[T]`, which would be undefined behavior on a misaligned
+/// buffer, so it returns `Err` when the start of `bytes` is not aligned to a pointer or when the
+/// data backing a slice or `Vec<T>` is not aligned to `align_of::<T>()`. A plain `Vec<u8>` makes no
+/// alignment promise, so bytes read off disk or a socket into one may be rejected. Read them into an
+/// `AlignedBytes` instead (it derefs to `&mut [u8]`, so any `Read::read_exact` works unchanged),
+/// then hand it to `decode_aligned`.
+pub struct AlignedBytes {
+    storage: Vec<u64>,
+    len: usize,
+}
+
+impl AlignedBytes {
+    /// Creates a zeroed, 8-byte-aligned buffer of `len` bytes.
+    pub fn new(len: usize) -> AlignedBytes {
+        AlignedBytes { storage: vec![0u64; (len + 7) / 8], len: len }
+    }
+}
+
+impl std::ops::Deref for AlignedBytes {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.storage.as_ptr() as *const u8, self.len) }
+    }
+}
+
+impl std::ops::DerefMut for AlignedBytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.storage.as_mut_ptr() as *mut u8, self.len) }
+    }
+}